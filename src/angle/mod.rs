@@ -0,0 +1,97 @@
+/// An angle measured in radians.
+#[derive(Clone, Copy, PartialEq, PartialOrd, Debug)]
+pub struct Rad<T>(T);
+
+/// An angle measured in degrees.
+#[derive(Clone, Copy, PartialEq, PartialOrd, Debug)]
+pub struct Deg<T>(T);
+
+impl<T: Copy> Rad<T> {
+    /// Creates a new angle from a value in radians.
+    ///
+    /// # Time complexity
+    ///
+    /// O(1)
+    pub fn new(value: T) -> Self {
+        Self(value)
+    }
+
+    /// Returns the angle as a raw radian value.
+    ///
+    /// # Time complexity
+    ///
+    /// O(1)
+    #[inline(always)]
+    pub fn value(&self) -> T {
+        self.0
+    }
+}
+
+impl<T: Copy> Deg<T> {
+    /// Creates a new angle from a value in degrees.
+    ///
+    /// # Time complexity
+    ///
+    /// O(1)
+    pub fn new(value: T) -> Self {
+        Self(value)
+    }
+
+    /// Returns the angle as a raw degree value.
+    ///
+    /// # Time complexity
+    ///
+    /// O(1)
+    #[inline(always)]
+    pub fn value(&self) -> T {
+        self.0
+    }
+}
+
+impl From<Deg<f64>> for Rad<f64> {
+    /// Converts an angle in degrees to radians.
+    fn from(deg: Deg<f64>) -> Self {
+        Rad(deg.value().to_radians())
+    }
+}
+
+impl From<Rad<f64>> for Deg<f64> {
+    /// Converts an angle in radians to degrees.
+    fn from(rad: Rad<f64>) -> Self {
+        Deg(rad.value().to_degrees())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EPSILON: f64 = 1e-9;
+
+    #[test]
+    fn deg_to_rad_matches_known_angles() {
+        assert!((Rad::from(Deg::new(0.0)).value() - 0.0).abs() <= EPSILON);
+        assert!(
+            (Rad::from(Deg::new(180.0)).value() - std::f64::consts::PI).abs() <= EPSILON
+        );
+        assert!(
+            (Rad::from(Deg::new(90.0)).value() - std::f64::consts::FRAC_PI_2).abs() <= EPSILON
+        );
+    }
+
+    #[test]
+    fn rad_to_deg_matches_known_angles() {
+        assert!((Deg::from(Rad::new(0.0)).value() - 0.0).abs() <= EPSILON);
+        assert!((Deg::from(Rad::new(std::f64::consts::PI)).value() - 180.0).abs() <= EPSILON);
+        assert!(
+            (Deg::from(Rad::new(std::f64::consts::FRAC_PI_2)).value() - 90.0).abs() <= EPSILON
+        );
+    }
+
+    #[test]
+    fn deg_rad_round_trip() {
+        let original = Deg::new(57.3);
+        let round_tripped = Deg::from(Rad::from(original));
+        assert!((round_tripped.value() - original.value()).abs() <= EPSILON);
+    }
+}
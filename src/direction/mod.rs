@@ -0,0 +1,142 @@
+use crate::Vector2D;
+
+/// One of the 8 compass directions.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum Direction {
+    N,
+    NE,
+    E,
+    SE,
+    S,
+    SW,
+    W,
+    NW,
+}
+
+impl Direction {
+    /// Returns the single-tile step vector for this direction.
+    ///
+    /// Axis-aligned directions have a Euclidean length of 1; diagonal
+    /// directions have components of ±1 in both axes (length √2), matching
+    /// how a single move is represented on a grid.
+    ///
+    /// # Time complexity
+    ///
+    /// O(1)
+    pub fn as_vec(&self) -> Vector2D<i64> {
+        match self {
+            Direction::N => Vector2D::new(0, 1),
+            Direction::NE => Vector2D::new(1, 1),
+            Direction::E => Vector2D::new(1, 0),
+            Direction::SE => Vector2D::new(1, -1),
+            Direction::S => Vector2D::new(0, -1),
+            Direction::SW => Vector2D::new(-1, -1),
+            Direction::W => Vector2D::new(-1, 0),
+            Direction::NW => Vector2D::new(-1, 1),
+        }
+    }
+
+    /// Returns whether this direction is diagonal (as opposed to
+    /// axis-aligned).
+    ///
+    /// # Time complexity
+    ///
+    /// O(1)
+    pub fn is_diagonal(&self) -> bool {
+        matches!(
+            self,
+            Direction::NE | Direction::SE | Direction::SW | Direction::NW
+        )
+    }
+}
+
+impl Vector2D<f64> {
+    /// Returns the compass direction closest to this vector's argument,
+    /// bucketing the angle returned by [`Self::arg`] into 45°-wide sectors
+    /// centered on each of the 8 directions.
+    ///
+    /// # Time complexity
+    ///
+    /// O(1)
+    pub fn nearest_direction(&self) -> Direction {
+        const DIRECTIONS: [Direction; 8] = [
+            Direction::E,
+            Direction::NE,
+            Direction::N,
+            Direction::NW,
+            Direction::W,
+            Direction::SW,
+            Direction::S,
+            Direction::SE,
+        ];
+        let sector_width = std::f64::consts::FRAC_PI_4;
+        let sector = ((self.arg().value() + sector_width / 2.0) / sector_width).floor() as i64;
+        DIRECTIONS[sector.rem_euclid(8) as usize]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A unit vector at `radians`, measured counter-clockwise from the
+    /// positive x-axis.
+    fn at(radians: f64) -> Vector2D<f64> {
+        Vector2D::new(radians.cos(), radians.sin())
+    }
+
+    #[test]
+    fn nearest_direction_matches_sector_centers() {
+        use std::f64::consts::FRAC_PI_4;
+        let centers = [
+            Direction::E,
+            Direction::NE,
+            Direction::N,
+            Direction::NW,
+            Direction::W,
+            Direction::SW,
+            Direction::S,
+            Direction::SE,
+        ];
+        for (k, &expected) in centers.iter().enumerate() {
+            assert_eq!(at(k as f64 * FRAC_PI_4).nearest_direction(), expected);
+        }
+    }
+
+    #[test]
+    fn nearest_direction_snaps_across_every_45_degree_boundary() {
+        use std::f64::consts::FRAC_PI_4;
+        let boundaries = [
+            (Direction::E, Direction::NE),
+            (Direction::NE, Direction::N),
+            (Direction::N, Direction::NW),
+            (Direction::NW, Direction::W),
+            (Direction::W, Direction::SW),
+            (Direction::SW, Direction::S),
+            (Direction::S, Direction::SE),
+            (Direction::SE, Direction::E),
+        ];
+        for (k, &(below, above)) in boundaries.iter().enumerate() {
+            let boundary = (k as f64 + 0.5) * FRAC_PI_4;
+            assert_eq!(at(boundary - 0.01).nearest_direction(), below);
+            assert_eq!(at(boundary + 0.01).nearest_direction(), above);
+        }
+    }
+
+    #[test]
+    fn as_vec_has_two_nonzero_components_iff_is_diagonal() {
+        for direction in [
+            Direction::N,
+            Direction::NE,
+            Direction::E,
+            Direction::SE,
+            Direction::S,
+            Direction::SW,
+            Direction::W,
+            Direction::NW,
+        ] {
+            let v = direction.as_vec();
+            assert_eq!(v.x() != 0 && v.y() != 0, direction.is_diagonal());
+        }
+    }
+}
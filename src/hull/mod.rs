@@ -0,0 +1,168 @@
+use crate::Vector2D;
+
+/// Sorts `points` by their argument (polar angle) around `pivot`, using
+/// [`Vector2D::arg_cmp`] on `point - pivot`.
+///
+/// Useful as a building block for Graham-scan-style convex hull algorithms.
+///
+/// # Panics
+///
+/// Panics if any point coincides with `pivot`, in debug builds (see
+/// [`Vector2D::arg_cmp`]).
+///
+/// # Time complexity
+///
+/// O(n log n)
+pub fn polar_sort<T>(points: &[Vector2D<T>], pivot: Vector2D<T>) -> Vec<Vector2D<T>>
+where
+    T: Ord + std::ops::Sub<Output = T> + std::ops::Mul<Output = T> + Copy + Default,
+{
+    let mut points = points.to_vec();
+    points.sort_by(|&a, &b| (a - pivot).arg_cmp(&(b - pivot)));
+    points
+}
+
+/// Returns the convex hull of `points`, in counter-clockwise order.
+///
+/// Computed via Andrew's monotone chain: `points` are sorted
+/// lexicographically by `(x, y)`, then the lower and upper hulls are built by
+/// scanning left-to-right and right-to-left respectively, popping the last
+/// hull point whenever the next point does not make a strict left turn.
+///
+/// Degenerate input is handled explicitly: fewer than 3 distinct points are
+/// returned deduplicated and in lexicographic order, and all-collinear input
+/// yields only its two lexicographic endpoints.
+///
+/// # Time complexity
+///
+/// O(n log n)
+pub fn convex_hull<T>(points: &[Vector2D<T>]) -> Vec<Vector2D<T>>
+where
+    T: Ord
+        + std::ops::Sub<Output = T>
+        + std::ops::Mul<Output = T>
+        + std::ops::Add<Output = T>
+        + Copy
+        + Default,
+{
+    let mut points = points.to_vec();
+    points.sort_by_key(|p| (p.x(), p.y()));
+    points.dedup();
+
+    if points.len() < 3 {
+        return points;
+    }
+
+    let turn = |o: Vector2D<T>, a: Vector2D<T>, b: Vector2D<T>| (a - o).cross(&(b - o));
+
+    let build = |points: &[Vector2D<T>]| -> Vec<Vector2D<T>> {
+        let mut hull: Vec<Vector2D<T>> = Vec::new();
+        for &p in points {
+            while hull.len() >= 2
+                && turn(hull[hull.len() - 2], hull[hull.len() - 1], p) <= T::default()
+            {
+                hull.pop();
+            }
+            hull.push(p);
+        }
+        hull
+    };
+
+    let mut lower = build(&points);
+    points.reverse();
+    let mut upper = build(&points);
+
+    lower.pop();
+    upper.pop();
+    lower.extend(upper);
+    lower
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn convex_hull_drops_interior_and_collinear_edge_points() {
+        let points = vec![
+            Vector2D::new(0i64, 0),
+            Vector2D::new(4, 0),
+            Vector2D::new(4, 4),
+            Vector2D::new(0, 4),
+            Vector2D::new(2, 2), // interior
+            Vector2D::new(2, 0), // collinear with a bottom edge
+        ];
+        let hull = convex_hull(&points);
+        assert_eq!(
+            hull,
+            vec![
+                Vector2D::new(0, 0),
+                Vector2D::new(4, 0),
+                Vector2D::new(4, 4),
+                Vector2D::new(0, 4),
+            ]
+        );
+    }
+
+    #[test]
+    fn convex_hull_all_collinear_returns_endpoints() {
+        let points = vec![
+            Vector2D::new(0i64, 0),
+            Vector2D::new(1, 1),
+            Vector2D::new(2, 2),
+            Vector2D::new(3, 3),
+        ];
+        let hull = convex_hull(&points);
+        assert_eq!(hull, vec![Vector2D::new(0, 0), Vector2D::new(3, 3)]);
+    }
+
+    #[test]
+    fn convex_hull_fewer_than_three_points_is_identity() {
+        assert_eq!(convex_hull::<i64>(&[]), vec![]);
+        assert_eq!(
+            convex_hull(&[Vector2D::new(1i64, 1)]),
+            vec![Vector2D::new(1, 1)]
+        );
+        let points = vec![Vector2D::new(0i64, 0), Vector2D::new(1, 1)];
+        assert_eq!(convex_hull(&points), points);
+    }
+
+    #[test]
+    fn convex_hull_triangle_is_exact() {
+        let points = vec![
+            Vector2D::new(0i64, 0),
+            Vector2D::new(4, 0),
+            Vector2D::new(0, 4),
+        ];
+        let hull = convex_hull(&points);
+        assert_eq!(
+            hull,
+            vec![
+                Vector2D::new(0, 0),
+                Vector2D::new(4, 0),
+                Vector2D::new(0, 4),
+            ]
+        );
+    }
+
+    #[test]
+    fn polar_sort_orders_points_counter_clockwise_around_pivot() {
+        let pivot = Vector2D::new(0i64, 0);
+        let points = vec![
+            Vector2D::new(0, 1),
+            Vector2D::new(1, 0),
+            Vector2D::new(-1, 0),
+            Vector2D::new(0, -1),
+        ];
+        let sorted = polar_sort(&points, pivot);
+        assert_eq!(
+            sorted,
+            vec![
+                Vector2D::new(1, 0),
+                Vector2D::new(0, 1),
+                Vector2D::new(-1, 0),
+                Vector2D::new(0, -1),
+            ]
+        );
+    }
+}
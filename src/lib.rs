@@ -0,0 +1,11 @@
+pub mod angle;
+pub mod direction;
+pub mod hull;
+pub mod polar2d;
+pub mod vector2d;
+
+pub use angle::{Deg, Rad};
+pub use direction::Direction;
+pub use hull::{convex_hull, polar_sort};
+pub use polar2d::Polar2D;
+pub use vector2d::Vector2D;
@@ -0,0 +1,113 @@
+use crate::Vector2D;
+
+/// A 2-dimensional vector in polar form: magnitude and angle.
+///
+/// The angle is measured counter-clockwise from the positive x-axis, in
+/// radians, following the same convention as [`Vector2D::arg_cmp`].
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Polar2D<T> {
+    magnitude: T,
+    angle: T,
+}
+
+impl<T: Copy> Polar2D<T> {
+    /// Creates a new polar vector from a magnitude and an angle in radians.
+    ///
+    /// # Time complexity
+    ///
+    /// O(1)
+    pub fn new(magnitude: T, angle: T) -> Self {
+        Self { magnitude, angle }
+    }
+
+    /// Returns the magnitude of the vector.
+    ///
+    /// # Time complexity
+    ///
+    /// O(1)
+    #[inline(always)]
+    pub fn magnitude(&self) -> T {
+        self.magnitude
+    }
+
+    /// Returns the angle of the vector, in radians.
+    ///
+    /// # Time complexity
+    ///
+    /// O(1)
+    #[inline(always)]
+    pub fn angle(&self) -> T {
+        self.angle
+    }
+}
+
+impl Polar2D<f64> {
+    /// Converts `self` to Cartesian coordinates.
+    ///
+    /// # Time complexity
+    ///
+    /// O(1)
+    pub fn to_cartesian(&self) -> Vector2D<f64> {
+        Vector2D::new(
+            self.magnitude() * self.angle().cos(),
+            self.magnitude() * self.angle().sin(),
+        )
+    }
+}
+
+impl Vector2D<f64> {
+    /// Converts `self` to polar coordinates.
+    ///
+    /// The angle is normalized to `[0, 2π)`, agreeing with the convention
+    /// documented on [`Vector2D::arg_cmp`].
+    ///
+    /// # Time complexity
+    ///
+    /// O(1)
+    pub fn to_polar(&self) -> Polar2D<f64> {
+        let magnitude = self.x().hypot(self.y());
+        let angle = self.y().atan2(self.x());
+        let angle = if angle < 0.0 {
+            angle + 2.0 * std::f64::consts::PI
+        } else {
+            angle
+        };
+        Polar2D::new(magnitude, angle)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EPSILON: f64 = 1e-9;
+
+    #[test]
+    fn to_polar_normalizes_negative_y_angle_into_0_2pi() {
+        // (0, -1) has a raw atan2 angle of -π/2, which must be normalized
+        // into [0, 2π) as 3π/2.
+        let polar = Vector2D::new(0.0, -1.0).to_polar();
+        assert!((polar.magnitude() - 1.0).abs() <= EPSILON);
+        assert!((polar.angle() - 3.0 * std::f64::consts::FRAC_PI_2).abs() <= EPSILON);
+    }
+
+    #[test]
+    fn to_polar_keeps_positive_y_angle_unchanged() {
+        let polar = Vector2D::new(0.0, 1.0).to_polar();
+        assert!((polar.magnitude() - 1.0).abs() <= EPSILON);
+        assert!((polar.angle() - std::f64::consts::FRAC_PI_2).abs() <= EPSILON);
+    }
+
+    #[test]
+    fn cartesian_to_polar_to_cartesian_round_trips() {
+        for v in [
+            Vector2D::new(3.0, 4.0),
+            Vector2D::new(-2.0, 5.0),
+            Vector2D::new(-1.0, -1.0),
+            Vector2D::new(2.0, -6.0),
+        ] {
+            let round_tripped = v.to_polar().to_cartesian();
+            assert!(v.approx_eq_default(&round_tripped));
+        }
+    }
+}
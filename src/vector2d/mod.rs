@@ -1,5 +1,5 @@
 /// A 2-dimensional vector over `T`.
-#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
 pub struct Vector2D<T>(T, T);
 
 /// Shorthand for constructing a `Vector2D`.
@@ -76,3 +76,321 @@ impl<T: Ord + std::ops::Mul<Output = T> + Copy + Default> Vector2D<T> {
             .then_with(|| (other.x() * self.y()).cmp(&(self.x() * other.y())))
     }
 }
+
+impl<T: std::ops::Add<Output = T> + Copy> std::ops::Add for Vector2D<T> {
+    type Output = Self;
+
+    /// Adds two vectors componentwise.
+    ///
+    /// # Time complexity
+    ///
+    /// O(1)
+    fn add(self, other: Self) -> Self {
+        Self(self.x() + other.x(), self.y() + other.y())
+    }
+}
+
+impl<T: std::ops::Sub<Output = T> + Copy> std::ops::Sub for Vector2D<T> {
+    type Output = Self;
+
+    /// Subtracts `other` from `self` componentwise.
+    ///
+    /// # Time complexity
+    ///
+    /// O(1)
+    fn sub(self, other: Self) -> Self {
+        Self(self.x() - other.x(), self.y() - other.y())
+    }
+}
+
+impl<T: std::ops::Neg<Output = T> + Copy> std::ops::Neg for Vector2D<T> {
+    type Output = Self;
+
+    /// Negates both components of the vector.
+    ///
+    /// # Time complexity
+    ///
+    /// O(1)
+    fn neg(self) -> Self {
+        Self(-self.x(), -self.y())
+    }
+}
+
+impl<T: std::ops::Mul<Output = T> + Copy> std::ops::Mul<T> for Vector2D<T> {
+    type Output = Self;
+
+    /// Scales the vector by a scalar.
+    ///
+    /// # Time complexity
+    ///
+    /// O(1)
+    fn mul(self, scalar: T) -> Self {
+        Self(self.x() * scalar, self.y() * scalar)
+    }
+}
+
+impl<T: std::ops::Div<Output = T> + Copy> std::ops::Div<T> for Vector2D<T> {
+    type Output = Self;
+
+    /// Divides the vector by a scalar.
+    ///
+    /// # Time complexity
+    ///
+    /// O(1)
+    fn div(self, scalar: T) -> Self {
+        Self(self.x() / scalar, self.y() / scalar)
+    }
+}
+
+impl<T: std::ops::Add<Output = T> + Copy> std::ops::AddAssign for Vector2D<T> {
+    /// Adds `other` into `self` componentwise.
+    ///
+    /// # Time complexity
+    ///
+    /// O(1)
+    fn add_assign(&mut self, other: Self) {
+        *self = *self + other;
+    }
+}
+
+impl<T: std::ops::Sub<Output = T> + Copy> std::ops::SubAssign for Vector2D<T> {
+    /// Subtracts `other` from `self` componentwise.
+    ///
+    /// # Time complexity
+    ///
+    /// O(1)
+    fn sub_assign(&mut self, other: Self) {
+        *self = *self - other;
+    }
+}
+
+impl<T: std::ops::Mul<Output = T> + std::ops::Add<Output = T> + Copy> Vector2D<T> {
+    /// Returns the dot product of `self` and `other`.
+    ///
+    /// # Time complexity
+    ///
+    /// O(1)
+    pub fn dot(&self, other: &Self) -> T {
+        self.x() * other.x() + self.y() * other.y()
+    }
+
+    /// Returns the squared Euclidean norm of the vector.
+    ///
+    /// # Time complexity
+    ///
+    /// O(1)
+    pub fn norm_sq(&self) -> T {
+        self.dot(self)
+    }
+}
+
+impl<T: std::ops::Mul<Output = T> + std::ops::Sub<Output = T> + Copy> Vector2D<T> {
+    /// Returns the 2D cross product of `self` and `other`, i.e.
+    /// `self.x() * other.y() - other.x() * self.y()`.
+    ///
+    /// # Time complexity
+    ///
+    /// O(1)
+    pub fn cross(&self, other: &Self) -> T {
+        self.x() * other.y() - other.x() * self.y()
+    }
+}
+
+/// Provides a default tolerance for approximate-equality comparisons.
+///
+/// Each float type overrides [`DefaultEpsilon::DEFAULT_EPSILON`] to suit its
+/// own precision.
+pub trait DefaultEpsilon {
+    /// The default tolerance used by `approx_eq_default`.
+    const DEFAULT_EPSILON: Self;
+}
+
+impl DefaultEpsilon for f64 {
+    const DEFAULT_EPSILON: Self = 1e-9;
+}
+
+impl DefaultEpsilon for f32 {
+    const DEFAULT_EPSILON: Self = 1e-5;
+}
+
+macro_rules! impl_approx_eq {
+    ($t:ty) => {
+        impl Vector2D<$t> {
+            /// Returns whether `self` and `other` are equal within `epsilon`,
+            /// componentwise.
+            ///
+            /// # Time complexity
+            ///
+            /// O(1)
+            pub fn approx_eq(&self, other: &Self, epsilon: $t) -> bool {
+                (self.x() - other.x()).abs() <= epsilon && (self.y() - other.y()).abs() <= epsilon
+            }
+
+            /// Returns whether `self` and `other` are equal within
+            /// [`DefaultEpsilon::DEFAULT_EPSILON`].
+            ///
+            /// # Time complexity
+            ///
+            /// O(1)
+            pub fn approx_eq_default(&self, other: &Self) -> bool {
+                self.approx_eq(other, <$t as DefaultEpsilon>::DEFAULT_EPSILON)
+            }
+        }
+    };
+}
+
+impl_approx_eq!(f32);
+impl_approx_eq!(f64);
+
+impl Vector2D<f64> {
+    /// Returns the Euclidean norm of the vector.
+    ///
+    /// # Time complexity
+    ///
+    /// O(1)
+    pub fn norm(&self) -> f64 {
+        self.norm_sq().sqrt()
+    }
+
+    /// Returns the argument (polar angle) of the vector.
+    ///
+    /// The argument is measured counter-clockwise from the positive x-axis,
+    /// ranging over [0, 2π), the same convention documented on `arg_cmp`.
+    ///
+    /// # Time complexity
+    ///
+    /// O(1)
+    pub fn arg(&self) -> crate::angle::Rad<f64> {
+        crate::angle::Rad::new(self.to_polar().angle())
+    }
+
+    /// Returns `self` rotated counter-clockwise by `angle`.
+    ///
+    /// # Time complexity
+    ///
+    /// O(1)
+    pub fn rotate_by(&self, angle: crate::angle::Rad<f64>) -> Self {
+        let (sin, cos) = angle.value().sin_cos();
+        Self::new(
+            self.x() * cos - self.y() * sin,
+            self.x() * sin + self.y() * cos,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn add_sub_neg_are_componentwise() {
+        let v = Vector2D::new(1, 2);
+        let w = Vector2D::new(3, 5);
+        assert_eq!(v + w, Vector2D::new(4, 7));
+        assert_eq!(w - v, Vector2D::new(2, 3));
+        assert_eq!(-v, Vector2D::new(-1, -2));
+    }
+
+    #[test]
+    fn add_then_sub_round_trips() {
+        let v = Vector2D::new(7, -3);
+        let w = Vector2D::new(2, 4);
+        assert_eq!(v + w - w, v);
+    }
+
+    #[test]
+    fn mul_and_div_scale_by_scalar() {
+        let v = Vector2D::new(2, -3);
+        assert_eq!(v * 5, Vector2D::new(10, -15));
+        assert_eq!(Vector2D::new(10, -15) / 5, v);
+    }
+
+    #[test]
+    fn add_assign_and_sub_assign_mutate_in_place() {
+        let mut v = Vector2D::new(1, 1);
+        v += Vector2D::new(2, 3);
+        assert_eq!(v, Vector2D::new(3, 4));
+        v -= Vector2D::new(2, 3);
+        assert_eq!(v, Vector2D::new(1, 1));
+    }
+
+    #[test]
+    fn dot_of_perpendicular_vectors_is_zero() {
+        assert_eq!(Vector2D::new(1, 0).dot(&Vector2D::new(0, 1)), 0);
+        assert_eq!(Vector2D::new(2, 3).dot(&Vector2D::new(2, 3)), 13);
+    }
+
+    #[test]
+    fn cross_sign_indicates_turn_direction() {
+        // (1, 0) to (0, 1) is a left (counter-clockwise) turn: positive.
+        assert!(Vector2D::new(1, 0).cross(&Vector2D::new(0, 1)) > 0);
+        // (0, 1) to (1, 0) is a right (clockwise) turn: negative.
+        assert!(Vector2D::new(0, 1).cross(&Vector2D::new(1, 0)) < 0);
+        // Parallel vectors have zero cross product.
+        assert_eq!(Vector2D::new(2, 4).cross(&Vector2D::new(1, 2)), 0);
+    }
+
+    #[test]
+    fn norm_sq_and_norm_on_a_3_4_5_triangle() {
+        let v = Vector2D::new(3.0, 4.0);
+        assert_eq!(v.norm_sq(), 25.0);
+        assert_eq!(v.norm(), 5.0);
+    }
+
+    #[test]
+    fn rotate_by_quarter_turns() {
+        let v = Vector2D::new(1.0, 0.0);
+        let quarter = crate::angle::Rad::new(std::f64::consts::FRAC_PI_2);
+
+        let rotated_90 = v.rotate_by(quarter);
+        assert!(rotated_90.approx_eq_default(&Vector2D::new(0.0, 1.0)));
+
+        let rotated_180 = rotated_90.rotate_by(quarter);
+        assert!(rotated_180.approx_eq_default(&Vector2D::new(-1.0, 0.0)));
+
+        let rotated_270 = rotated_180.rotate_by(quarter);
+        assert!(rotated_270.approx_eq_default(&Vector2D::new(0.0, -1.0)));
+
+        let rotated_360 = rotated_270.rotate_by(quarter);
+        assert!(rotated_360.approx_eq_default(&v));
+    }
+
+    #[test]
+    fn arg_matches_axis_aligned_angles() {
+        let close = |a: f64, b: f64| (a - b).abs() <= f64::DEFAULT_EPSILON;
+        assert!(close(Vector2D::new(1.0, 0.0).arg().value(), 0.0));
+        assert!(close(
+            Vector2D::new(0.0, 1.0).arg().value(),
+            std::f64::consts::FRAC_PI_2
+        ));
+        assert!(close(
+            Vector2D::new(-1.0, 0.0).arg().value(),
+            std::f64::consts::PI
+        ));
+    }
+
+    #[test]
+    fn approx_eq_boundary_is_inclusive() {
+        // 0.5 is exactly representable, so the componentwise diff equals
+        // `epsilon` exactly, making this a genuine `<=` boundary check.
+        let v = Vector2D::new(0.0_f64, 0.0);
+        let w = Vector2D::new(0.5_f64, 0.0);
+        assert!(v.approx_eq(&w, 0.5));
+        assert!(!v.approx_eq(&w, 0.5 - f64::EPSILON));
+    }
+
+    #[test]
+    fn approx_eq_default_uses_per_type_epsilon() {
+        let v64 = Vector2D::new(1.0_f64, 1.0);
+        let w64 = Vector2D::new(1.0 + f64::DEFAULT_EPSILON / 2.0, 1.0);
+        assert!(v64.approx_eq_default(&w64));
+        let far64 = Vector2D::new(1.0 + f64::DEFAULT_EPSILON * 2.0, 1.0);
+        assert!(!v64.approx_eq_default(&far64));
+
+        let v32 = Vector2D::new(1.0_f32, 1.0);
+        let w32 = Vector2D::new(1.0 + f32::DEFAULT_EPSILON / 2.0, 1.0);
+        assert!(v32.approx_eq_default(&w32));
+        let far32 = Vector2D::new(1.0 + f32::DEFAULT_EPSILON * 2.0, 1.0);
+        assert!(!v32.approx_eq_default(&far32));
+    }
+}